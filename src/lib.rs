@@ -28,9 +28,10 @@
 #[cfg(any(std, test))]
 extern crate core;
 
-use core::{iter, slice};
+use core::{cmp, iter, slice};
+use core::marker::PhantomData;
 use core::ops::{Index, IndexMut};
-use core::ops::Range;
+use core::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo};
 
 /// Adds `.rev()` and `.rev_mut()` methods to slices.
 ///
@@ -51,6 +52,10 @@ pub trait SliceExt {
 
 mod internal {
     pub struct Sealed;
+
+    /// Seals the `RevSliceIndex` trait so it can only be implemented for the
+    /// index types provided by this crate.
+    pub trait SealedIndex {}
 }
 
 impl<T> SliceExt for [T] {
@@ -150,35 +155,190 @@ impl<T> RevSlice<T> {
         let (a, b) = self.0.split_at_mut(rmid);
         (b.rev_mut(), a.rev_mut())
     }
+
+    /// Returns a reference to an element or subslice, or `None` if out of bounds.
+    ///
+    /// Accepts `usize`, `Range`, `RangeTo`, `RangeFrom`, `RangeFull` and
+    /// `RangeInclusive`; `RangeToInclusive` (`..=b`) is not supported.
+    pub fn get<I: RevSliceIndex<T>>(&self, index: I) -> Option<&I::Output> {
+        index.get(self)
+    }
+
+    /// Returns a mutable reference to an element or subslice, or `None` if out of bounds.
+    pub fn get_mut<I: RevSliceIndex<T>>(&mut self, index: I) -> Option<&mut I::Output> {
+        index.get_mut(self)
+    }
 }
 
-impl<T> Index<usize> for RevSlice<T> {
+/// A helper trait used for indexing operations on `RevSlice`, mirroring
+/// `core`'s `SliceIndex`.
+///
+/// There's no reason to implement this yourself.
+pub trait RevSliceIndex<T>: internal::SealedIndex {
+    /// The output type returned by this kind of index.
+    type Output: ?Sized;
+
+    /// Returns a shared reference to the output, or `None` if out of bounds.
+    fn get(self, slice: &RevSlice<T>) -> Option<&Self::Output>;
+
+    /// Returns a mutable reference to the output, or `None` if out of bounds.
+    fn get_mut(self, slice: &mut RevSlice<T>) -> Option<&mut Self::Output>;
+
+    /// Returns a shared reference to the output, panicking if out of bounds.
+    fn index(self, slice: &RevSlice<T>) -> &Self::Output;
+
+    /// Returns a mutable reference to the output, panicking if out of bounds.
+    fn index_mut(self, slice: &mut RevSlice<T>) -> &mut Self::Output;
+}
+
+impl internal::SealedIndex for usize {}
+impl internal::SealedIndex for Range<usize> {}
+impl internal::SealedIndex for RangeTo<usize> {}
+impl internal::SealedIndex for RangeFrom<usize> {}
+impl internal::SealedIndex for RangeFull {}
+impl internal::SealedIndex for RangeInclusive<usize> {}
+
+impl<T> RevSliceIndex<T> for usize {
     type Output = T;
-    fn index(&self, index: usize) -> &Self::Output {
-        let rindex = self.flip_index(index);
-        &self.0[rindex]
+    fn get(self, slice: &RevSlice<T>) -> Option<&T> {
+        if self < slice.len() {
+            Some(&slice.0[slice.flip_index(self)])
+        } else {
+            None
+        }
+    }
+    fn get_mut(self, slice: &mut RevSlice<T>) -> Option<&mut T> {
+        if self < slice.len() {
+            let rindex = slice.flip_index(self);
+            Some(&mut slice.0[rindex])
+        } else {
+            None
+        }
+    }
+    fn index(self, slice: &RevSlice<T>) -> &T {
+        let rindex = slice.flip_index(self);
+        &slice.0[rindex]
+    }
+    fn index_mut(self, slice: &mut RevSlice<T>) -> &mut T {
+        let rindex = slice.flip_index(self);
+        &mut slice.0[rindex]
     }
 }
 
-impl<T> IndexMut<usize> for RevSlice<T> {
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        let rindex = self.flip_index(index);
-        &mut self.0[rindex]
+impl<T> RevSliceIndex<T> for Range<usize> {
+    type Output = RevSlice<T>;
+    fn get(self, slice: &RevSlice<T>) -> Option<&RevSlice<T>> {
+        if self.start > self.end || self.end > slice.len() {
+            None
+        } else {
+            let rrange = slice.flip_range(self);
+            Some(slice.0[rrange].rev())
+        }
+    }
+    fn get_mut(self, slice: &mut RevSlice<T>) -> Option<&mut RevSlice<T>> {
+        if self.start > self.end || self.end > slice.len() {
+            None
+        } else {
+            let rrange = slice.flip_range(self);
+            Some(slice.0[rrange].rev_mut())
+        }
+    }
+    fn index(self, slice: &RevSlice<T>) -> &RevSlice<T> {
+        self.get(slice).expect("range out of bounds")
+    }
+    fn index_mut(self, slice: &mut RevSlice<T>) -> &mut RevSlice<T> {
+        self.get_mut(slice).expect("range out of bounds")
     }
 }
 
-impl<T> Index<Range<usize>> for RevSlice<T> {
+impl<T> RevSliceIndex<T> for RangeTo<usize> {
     type Output = RevSlice<T>;
-    fn index(&self, index: Range<usize>) -> &Self::Output {
-        let rindex = self.flip_range(index);
-        self.0[rindex].rev()
+    fn get(self, slice: &RevSlice<T>) -> Option<&RevSlice<T>> {
+        (0..self.end).get(slice)
+    }
+    fn get_mut(self, slice: &mut RevSlice<T>) -> Option<&mut RevSlice<T>> {
+        (0..self.end).get_mut(slice)
+    }
+    fn index(self, slice: &RevSlice<T>) -> &RevSlice<T> {
+        (0..self.end).index(slice)
+    }
+    fn index_mut(self, slice: &mut RevSlice<T>) -> &mut RevSlice<T> {
+        (0..self.end).index_mut(slice)
+    }
+}
+
+impl<T> RevSliceIndex<T> for RangeFrom<usize> {
+    type Output = RevSlice<T>;
+    fn get(self, slice: &RevSlice<T>) -> Option<&RevSlice<T>> {
+        let len = slice.len();
+        (self.start..len).get(slice)
+    }
+    fn get_mut(self, slice: &mut RevSlice<T>) -> Option<&mut RevSlice<T>> {
+        let len = slice.len();
+        (self.start..len).get_mut(slice)
+    }
+    fn index(self, slice: &RevSlice<T>) -> &RevSlice<T> {
+        let len = slice.len();
+        (self.start..len).index(slice)
+    }
+    fn index_mut(self, slice: &mut RevSlice<T>) -> &mut RevSlice<T> {
+        let len = slice.len();
+        (self.start..len).index_mut(slice)
+    }
+}
+
+impl<T> RevSliceIndex<T> for RangeFull {
+    type Output = RevSlice<T>;
+    fn get(self, slice: &RevSlice<T>) -> Option<&RevSlice<T>> {
+        Some(slice)
+    }
+    fn get_mut(self, slice: &mut RevSlice<T>) -> Option<&mut RevSlice<T>> {
+        Some(slice)
+    }
+    fn index(self, slice: &RevSlice<T>) -> &RevSlice<T> {
+        slice
+    }
+    fn index_mut(self, slice: &mut RevSlice<T>) -> &mut RevSlice<T> {
+        slice
+    }
+}
+
+impl<T> RevSliceIndex<T> for RangeInclusive<usize> {
+    type Output = RevSlice<T>;
+    fn get(self, slice: &RevSlice<T>) -> Option<&RevSlice<T>> {
+        if *self.end() == usize::max_value() {
+            None
+        } else {
+            (*self.start()..*self.end() + 1).get(slice)
+        }
+    }
+    fn get_mut(self, slice: &mut RevSlice<T>) -> Option<&mut RevSlice<T>> {
+        if *self.end() == usize::max_value() {
+            None
+        } else {
+            (*self.start()..*self.end() + 1).get_mut(slice)
+        }
+    }
+    fn index(self, slice: &RevSlice<T>) -> &RevSlice<T> {
+        assert!(*self.end() != usize::max_value(), "attempted to index slice up to maximum usize");
+        (*self.start()..*self.end() + 1).index(slice)
+    }
+    fn index_mut(self, slice: &mut RevSlice<T>) -> &mut RevSlice<T> {
+        assert!(*self.end() != usize::max_value(), "attempted to index slice up to maximum usize");
+        (*self.start()..*self.end() + 1).index_mut(slice)
     }
 }
 
-impl<T> IndexMut<Range<usize>> for RevSlice<T> {
-    fn index_mut(&mut self, index: Range<usize>) -> &mut Self::Output {
-        let rindex = self.flip_range(index);
-        self.0[rindex].rev_mut()
+impl<T, I: RevSliceIndex<T>> Index<I> for RevSlice<T> {
+    type Output = I::Output;
+    fn index(&self, index: I) -> &Self::Output {
+        index.index(self)
+    }
+}
+
+impl<T, I: RevSliceIndex<T>> IndexMut<I> for RevSlice<T> {
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        index.index_mut(self)
     }
 }
 
@@ -192,6 +352,312 @@ impl<T> RevSlice<T> {
     pub fn iter_mut(&mut self) -> iter::Rev<slice::IterMut<T>> {
         self.0.iter_mut().rev()
     }
+
+    /// Returns an iterator over `chunk_size` elements of the view at a time,
+    /// starting at the front, re-wrapping each underlying chunk as a `RevSlice`.
+    ///
+    /// The chunks are views into non-overlapping parts of the original slice,
+    /// and the last chunk may be shorter than `chunk_size` if the view's
+    /// length isn't evenly divided by it.
+    pub fn chunks(&self, chunk_size: usize) -> iter::Map<slice::RChunks<T>, fn(&[T]) -> &RevSlice<T>> {
+        self.0.rchunks(chunk_size).map(wrap)
+    }
+
+    /// Returns an iterator over `chunk_size` elements of the view at a time,
+    /// starting at the front, re-wrapping each underlying chunk as a `&mut RevSlice`.
+    pub fn chunks_mut(&mut self, chunk_size: usize) -> iter::Map<slice::RChunksMut<T>, fn(&mut [T]) -> &mut RevSlice<T>> {
+        self.0.rchunks_mut(chunk_size).map(wrap_mut)
+    }
+
+    /// Like [`chunks`](#method.chunks), but chunks are counted from the back
+    /// of the view instead of the front.
+    pub fn rchunks(&self, chunk_size: usize) -> iter::Map<slice::Chunks<T>, fn(&[T]) -> &RevSlice<T>> {
+        self.0.chunks(chunk_size).map(wrap)
+    }
+
+    /// Returns an iterator over all contiguous windows of length `size`,
+    /// sliding one element at a time from the front of the view.
+    pub fn windows(&self, size: usize) -> iter::Map<iter::Rev<slice::Windows<T>>, fn(&[T]) -> &RevSlice<T>> {
+        self.0.windows(size).rev().map(wrap)
+    }
+
+    /// Returns an iterator over subslices of the view separated by elements
+    /// that match `pred`, starting from the front. Matched elements are not
+    /// contained in the subslices.
+    pub fn split<F>(&self, pred: F) -> iter::Map<slice::RSplit<T, F>, fn(&[T]) -> &RevSlice<T>>
+        where F: FnMut(&T) -> bool
+    {
+        self.0.rsplit(pred).map(wrap)
+    }
+
+    /// Like [`split`](#method.split), but the subslices are yielded starting
+    /// from the back of the view instead of the front.
+    pub fn rsplit<F>(&self, pred: F) -> iter::Map<slice::Split<T, F>, fn(&[T]) -> &RevSlice<T>>
+        where F: FnMut(&T) -> bool
+    {
+        self.0.split(pred).map(wrap)
+    }
+
+    /// Like [`split`](#method.split), but stops splitting after `n` subslices
+    /// are yielded, with the last one containing the rest of the view.
+    pub fn splitn<F>(&self, n: usize, pred: F) -> iter::Map<slice::RSplitN<T, F>, fn(&[T]) -> &RevSlice<T>>
+        where F: FnMut(&T) -> bool
+    {
+        self.0.rsplitn(n, pred).map(wrap)
+    }
+
+    /// Like [`rsplit`](#method.rsplit), but stops splitting after `n`
+    /// subslices are yielded, with the last one containing the rest of the view.
+    pub fn rsplitn<F>(&self, n: usize, pred: F) -> iter::Map<slice::SplitN<T, F>, fn(&[T]) -> &RevSlice<T>>
+        where F: FnMut(&T) -> bool
+    {
+        self.0.splitn(n, pred).map(wrap)
+    }
+
+    /// Binary searches the view for `f`, treating the view itself (not the
+    /// underlying storage) as sorted, and returns the matching view index.
+    ///
+    /// Returns `Ok(index)` for a match, or `Err(index)` for the position
+    /// where a matching element could be inserted to keep the view sorted.
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+        where F: FnMut(&T) -> cmp::Ordering
+    {
+        let mut lo = 0;
+        let mut hi = self.len();
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            match f(&self[mid]) {
+                cmp::Ordering::Less => lo = mid + 1,
+                cmp::Ordering::Greater => hi = mid,
+                cmp::Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(lo)
+    }
+
+    /// Like [`binary_search_by`](#method.binary_search_by), but the view is
+    /// searched via a key extracted from each element.
+    pub fn binary_search_by_key<B, F>(&self, b: &B, mut f: F) -> Result<usize, usize>
+        where B: Ord, F: FnMut(&T) -> B
+    {
+        self.binary_search_by(|k| f(k).cmp(b))
+    }
+}
+
+impl<T: Ord> RevSlice<T> {
+    /// Binary searches the view for `x`, treating the view itself (not the
+    /// underlying storage) as sorted, and returns the matching view index.
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize> {
+        self.binary_search_by(|p| p.cmp(x))
+    }
+}
+
+impl<T> RevSlice<T> {
+    /// Swaps the elements at view positions `a` and `b`.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        let ra = self.flip_index(a);
+        let rb = self.flip_index(b);
+        self.0.swap(ra, rb);
+    }
+
+    /// Reverses the order of the elements in the view, in place.
+    pub fn reverse(&mut self) {
+        self.0.reverse();
+    }
+
+    /// Rotates the view in place such that the first `mid` elements of the
+    /// view move to the end of the view.
+    pub fn rotate_left(&mut self, mid: usize) {
+        self.0.rotate_right(mid);
+    }
+
+    /// Rotates the view in place such that the last `k` elements of the
+    /// view move to the front of the view.
+    pub fn rotate_right(&mut self, k: usize) {
+        self.0.rotate_left(k);
+    }
+}
+
+impl<T: Clone> RevSlice<T> {
+    /// Fills every element of the view with `value`.
+    pub fn fill(&mut self, value: T) {
+        self.0.fill(value);
+    }
+
+    /// Clones the elements of `src` into the view, in view order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` and `src` have different lengths.
+    pub fn clone_from_slice(&mut self, src: &[T]) {
+        assert_eq!(self.len(), src.len(), "destination and source slices have different lengths");
+        for (dst, s) in self.iter_mut().zip(src) {
+            *dst = s.clone();
+        }
+    }
+}
+
+impl<T: Copy> RevSlice<T> {
+    /// Copies the elements of `src` into the view, in view order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` and `src` have different lengths.
+    pub fn copy_from_slice(&mut self, src: &[T]) {
+        assert_eq!(self.len(), src.len(), "destination and source slices have different lengths");
+        for (dst, &v) in self.iter_mut().zip(src) {
+            *dst = v;
+        }
+    }
+}
+
+impl<T> RevSlice<T> {
+    /// Returns a view of `self` that is indexed by a user-defined index type
+    /// `I` instead of by `usize`, for callers who key into slices with
+    /// strongly-typed indices (e.g. from the `index_vec` crate).
+    pub fn rev_indexed<I>(&self) -> RevSliceIndexed<T, I> {
+        RevSliceIndexed { slice: self, _marker: PhantomData }
+    }
+
+    /// Returns a mutable view of `self` that is indexed by a user-defined
+    /// index type `I` instead of by `usize`.
+    pub fn rev_indexed_mut<I>(&mut self) -> RevSliceIndexedMut<T, I> {
+        RevSliceIndexedMut { slice: self, _marker: PhantomData }
+    }
+}
+
+/// A view of a `RevSlice` indexed by a user-defined index type `I` rather
+/// than by `usize`, following the pattern used by crates like `index_vec`.
+///
+/// There's no reason to construct this yourself; use
+/// [`RevSlice::rev_indexed`](struct.RevSlice.html#method.rev_indexed).
+pub struct RevSliceIndexed<'a, T: 'a, I> {
+    slice: &'a RevSlice<T>,
+    _marker: PhantomData<fn(I)>,
+}
+
+impl<'a, T, I: Into<usize>> RevSliceIndexed<'a, T, I> {
+    /// Returns a reference to the element at view position `index`, or
+    /// `None` if out of bounds.
+    pub fn get(&self, index: I) -> Option<&'a T> {
+        self.slice.get(index.into())
+    }
+
+    /// Divides the view into two at `mid`, each still indexed by `I`.
+    pub fn split_at(&self, mid: I) -> (RevSliceIndexed<'a, T, I>, RevSliceIndexed<'a, T, I>) {
+        let (a, b) = self.slice.split_at(mid.into());
+        (RevSliceIndexed { slice: a, _marker: PhantomData },
+         RevSliceIndexed { slice: b, _marker: PhantomData })
+    }
+}
+
+impl<'a, T, I> RevSliceIndexed<'a, T, I> {
+    /// Returns an iterator yielding `(I, &T)` pairs, where `I` is the view
+    /// index (0 at the view's front), not the underlying storage index.
+    pub fn iter_enumerated(&self) -> IterEnumerated<'a, T, I> {
+        IterEnumerated { inner: self.slice.iter().enumerate(), _marker: PhantomData }
+    }
+}
+
+impl<'a, T, I: Into<usize>> Index<I> for RevSliceIndexed<'a, T, I> {
+    type Output = T;
+    fn index(&self, index: I) -> &T {
+        &self.slice[index.into()]
+    }
+}
+
+/// Iterator over `(I, &T)` pairs in view order, produced by
+/// [`RevSliceIndexed::iter_enumerated`](struct.RevSliceIndexed.html#method.iter_enumerated).
+pub struct IterEnumerated<'a, T: 'a, I> {
+    inner: iter::Enumerate<iter::Rev<slice::Iter<'a, T>>>,
+    _marker: PhantomData<fn(I)>,
+}
+
+impl<'a, T, I: From<usize>> Iterator for IterEnumerated<'a, T, I> {
+    type Item = (I, &'a T);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(i, v)| (I::from(i), v))
+    }
+}
+
+/// A mutable view of a `RevSlice` indexed by a user-defined index type `I`
+/// rather than by `usize`.
+///
+/// There's no reason to construct this yourself; use
+/// [`RevSlice::rev_indexed_mut`](struct.RevSlice.html#method.rev_indexed_mut).
+pub struct RevSliceIndexedMut<'a, T: 'a, I> {
+    slice: &'a mut RevSlice<T>,
+    _marker: PhantomData<fn(I)>,
+}
+
+impl<'a, T, I: Into<usize>> RevSliceIndexedMut<'a, T, I> {
+    /// Returns a reference to the element at view position `index`, or
+    /// `None` if out of bounds.
+    pub fn get(&self, index: I) -> Option<&T> {
+        self.slice.get(index.into())
+    }
+
+    /// Returns a mutable reference to the element at view position `index`,
+    /// or `None` if out of bounds.
+    pub fn get_mut(&mut self, index: I) -> Option<&mut T> {
+        self.slice.get_mut(index.into())
+    }
+
+    /// Divides the mutable view into two at `mid`, each still indexed by `I`.
+    pub fn split_at_mut(&mut self, mid: I) -> (RevSliceIndexedMut<T, I>, RevSliceIndexedMut<T, I>) {
+        let (a, b) = self.slice.split_at_mut(mid.into());
+        (RevSliceIndexedMut { slice: a, _marker: PhantomData },
+         RevSliceIndexedMut { slice: b, _marker: PhantomData })
+    }
+}
+
+impl<'a, T, I> RevSliceIndexedMut<'a, T, I> {
+    /// Returns an iterator yielding `(I, &T)` pairs, where `I` is the view
+    /// index (0 at the view's front), not the underlying storage index.
+    pub fn iter_enumerated(&self) -> IterEnumerated<T, I> {
+        IterEnumerated { inner: self.slice.iter().enumerate(), _marker: PhantomData }
+    }
+
+    /// Returns an iterator yielding `(I, &mut T)` pairs, where `I` is the
+    /// view index (0 at the view's front), not the underlying storage index.
+    pub fn iter_enumerated_mut(&mut self) -> IterEnumeratedMut<T, I> {
+        IterEnumeratedMut { inner: self.slice.iter_mut().enumerate(), _marker: PhantomData }
+    }
+}
+
+impl<'a, T, I: Into<usize>> Index<I> for RevSliceIndexedMut<'a, T, I> {
+    type Output = T;
+    fn index(&self, index: I) -> &T {
+        &self.slice[index.into()]
+    }
+}
+
+impl<'a, T, I: Into<usize>> IndexMut<I> for RevSliceIndexedMut<'a, T, I> {
+    fn index_mut(&mut self, index: I) -> &mut T {
+        &mut self.slice[index.into()]
+    }
+}
+
+/// Iterator over `(I, &mut T)` pairs in view order, produced by
+/// [`RevSliceIndexedMut::iter_enumerated_mut`](struct.RevSliceIndexedMut.html#method.iter_enumerated_mut).
+pub struct IterEnumeratedMut<'a, T: 'a, I> {
+    inner: iter::Enumerate<iter::Rev<slice::IterMut<'a, T>>>,
+    _marker: PhantomData<fn(I)>,
+}
+
+impl<'a, T, I: From<usize>> Iterator for IterEnumeratedMut<'a, T, I> {
+    type Item = (I, &'a mut T);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(i, v)| (I::from(i), v))
+    }
+}
+
+fn wrap<T>(s: &[T]) -> &RevSlice<T> {
+    s.rev()
+}
+
+fn wrap_mut<T>(s: &mut [T]) -> &mut RevSlice<T> {
+    s.rev_mut()
 }
 
 impl<'a, T> iter::IntoIterator for &'a RevSlice<T> {
@@ -240,4 +706,180 @@ mod tests {
     fn iter_works_too() {
         assert_eq!((0..10).rev().nth(1), Some(8));
     }
+
+    #[test]
+    fn get_and_ranges() {
+        let a = [1, 2, 3, 4, 5];
+        let r = a.rev();
+
+        assert_eq!(r.get(0), Some(&5));
+        assert_eq!(r.get(5), None);
+        assert_eq!(r.get(..2).unwrap().rev(), &[4, 5]);
+        assert_eq!(r.get(2..).unwrap().rev(), &[1, 2, 3]);
+        assert_eq!(r.get(..).unwrap().rev(), &a);
+        assert_eq!(r.get(1..=2).unwrap().rev(), &[3, 4]);
+        assert_eq!(r.get(10..20), None);
+
+        let mut a = [1, 2, 3, 4, 5];
+        {
+            let r = a.rev_mut();
+            let sub = r.get_mut(1..3).unwrap();
+            sub[0] = 40;
+            sub[1] = 30;
+        }
+        assert_eq!(a, [1, 2, 30, 40, 5]);
+    }
+
+    #[test]
+    fn chunks_rchunks_windows() {
+        let a = [1, 2, 3, 4, 5, 6, 7];
+        let r = a.rev();
+
+        let chunks: Vec<_> = r.chunks(3).map(|c| c.iter().cloned().collect::<Vec<_>>()).collect();
+        assert_eq!(chunks, vec![vec![7, 6, 5], vec![4, 3, 2], vec![1]]);
+
+        let rchunks: Vec<_> = r.rchunks(3).map(|c| c.iter().cloned().collect::<Vec<_>>()).collect();
+        assert_eq!(rchunks, vec![vec![3, 2, 1], vec![6, 5, 4], vec![7]]);
+
+        let windows: Vec<_> = r.windows(3).map(|w| w.iter().cloned().collect::<Vec<_>>()).collect();
+        assert_eq!(windows[0], vec![7, 6, 5]);
+        assert_eq!(windows[1], vec![6, 5, 4]);
+        assert_eq!(*windows.last().unwrap(), vec![3, 2, 1]);
+
+        let mut b = [1, 2, 3, 4, 5, 6, 7];
+        {
+            let r = b.rev_mut();
+            let mut it = r.chunks_mut(3);
+            let first = it.next().unwrap();
+            assert_eq!(first.iter().cloned().collect::<Vec<_>>(), vec![7, 6, 5]);
+            first[0] = 70;
+        }
+        assert_eq!(b, [1, 2, 3, 4, 5, 6, 70]);
+    }
+
+    #[test]
+    fn split_families() {
+        let a = [1, 2, 0, 3, 4, 0, 5];
+        let r = a.rev();
+
+        let split: Vec<_> = r.split(|&x| x == 0).map(|s| s.iter().cloned().collect::<Vec<_>>()).collect();
+        assert_eq!(split, vec![vec![5], vec![4, 3], vec![2, 1]]);
+
+        let rsplit: Vec<_> = r.rsplit(|&x| x == 0).map(|s| s.iter().cloned().collect::<Vec<_>>()).collect();
+        assert_eq!(rsplit, vec![vec![2, 1], vec![4, 3], vec![5]]);
+
+        let splitn: Vec<_> = r.splitn(2, |&x| x == 0).map(|s| s.iter().cloned().collect::<Vec<_>>()).collect();
+        assert_eq!(splitn, vec![vec![5], vec![4, 3, 0, 2, 1]]);
+
+        let rsplitn: Vec<_> = r.rsplitn(2, |&x| x == 0).map(|s| s.iter().cloned().collect::<Vec<_>>()).collect();
+        assert_eq!(rsplitn, vec![vec![2, 1], vec![5, 0, 4, 3]]);
+    }
+
+    #[test]
+    fn binary_search_on_sorted_view() {
+        let a = [5, 4, 3, 2, 1];
+        let r = a.rev();
+        assert_eq!(r.iter().cloned().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+
+        assert_eq!(r.binary_search(&3), Ok(2));
+        assert_eq!(r.binary_search(&1), Ok(0));
+        assert_eq!(r.binary_search(&5), Ok(4));
+        assert_eq!(r.binary_search(&0), Err(0));
+        assert_eq!(r.binary_search(&6), Err(5));
+        assert_eq!(r.binary_search_by_key(&3, |&x| x), Ok(2));
+    }
+
+    #[test]
+    fn mutators_work_in_view_space() {
+        let mut a = [1, 2, 3, 4, 5];
+        {
+            let r = a.rev_mut();
+            r.swap(0, 4);
+            assert_eq!(r.iter().cloned().collect::<Vec<_>>(), vec![1, 4, 3, 2, 5]);
+        }
+        assert_eq!(a, [5, 2, 3, 4, 1]);
+
+        let mut b = [1, 2, 3, 4, 5];
+        b.rev_mut().reverse();
+        assert_eq!(b.rev().iter().cloned().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+
+        let mut c = [1, 2, 3, 4, 5];
+        c.rev_mut().rotate_left(2);
+        assert_eq!(c.rev().iter().cloned().collect::<Vec<_>>(), vec![3, 2, 1, 5, 4]);
+
+        let mut d = [0, 0, 0];
+        d.rev_mut().fill(9);
+        assert_eq!(d, [9, 9, 9]);
+
+        let mut e = [0, 0, 0];
+        e.rev_mut().copy_from_slice(&[1, 2, 3]);
+        assert_eq!(e, [3, 2, 1]);
+
+        let mut f = [1, 2, 3, 4, 5];
+        f.rev_mut().rotate_right(2);
+        assert_eq!(f.rev().iter().cloned().collect::<Vec<_>>(), vec![2, 1, 5, 4, 3]);
+
+        let mut g = [0, 0, 0];
+        g.rev_mut().clone_from_slice(&[1, 2, 3]);
+        assert_eq!(g, [3, 2, 1]);
+    }
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    struct Idx(usize);
+
+    impl From<usize> for Idx {
+        fn from(i: usize) -> Idx { Idx(i) }
+    }
+
+    impl From<Idx> for usize {
+        fn from(i: Idx) -> usize { i.0 }
+    }
+
+    #[test]
+    fn typed_index_adapter() {
+        let a = [1, 2, 3, 4, 5];
+        let r = a.rev();
+        let indexed = r.rev_indexed::<Idx>();
+
+        assert_eq!(indexed.get(Idx(0)), Some(&5));
+        assert_eq!(indexed[Idx(1)], 4);
+        assert_eq!(indexed.get(Idx(5)), None);
+
+        let pairs: Vec<_> = indexed.iter_enumerated().map(|(i, &v)| (i, v)).collect();
+        assert_eq!(pairs, vec![(Idx(0), 5), (Idx(1), 4), (Idx(2), 3), (Idx(3), 2), (Idx(4), 1)]);
+
+        let (left, right) = indexed.split_at(Idx(2));
+        assert_eq!(left.get(Idx(0)), Some(&5));
+        assert_eq!(right.get(Idx(0)), Some(&3));
+    }
+
+    #[test]
+    fn typed_index_adapter_mut() {
+        let mut a = [1, 2, 3, 4, 5];
+        {
+            let mut indexed = a.rev_mut().rev_indexed_mut::<Idx>();
+            *indexed.get_mut(Idx(0)).unwrap() = 50;
+            indexed[Idx(1)] = 40;
+        }
+        assert_eq!(a, [1, 2, 3, 40, 50]);
+
+        let mut b = [1, 2, 3, 4, 5];
+        {
+            let mut indexed = b.rev_mut().rev_indexed_mut::<Idx>();
+            for (i, v) in indexed.iter_enumerated_mut() {
+                if i == Idx(2) {
+                    *v = 99;
+                }
+            }
+        }
+        assert_eq!(b.rev().iter().cloned().collect::<Vec<_>>(), vec![5, 4, 99, 2, 1]);
+
+        let mut c = [1, 2, 3, 4, 5];
+        {
+            let mut indexed = c.rev_mut().rev_indexed_mut::<Idx>();
+            let (left, right) = indexed.split_at_mut(Idx(2));
+            assert_eq!(left.get(Idx(0)), Some(&5));
+            assert_eq!(right.get(Idx(0)), Some(&3));
+        }
+    }
 }